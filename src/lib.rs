@@ -2,13 +2,14 @@ use bevy::app::{App, Plugin};
 use bevy::asset::{Asset, AssetEvent, Assets, Handle};
 use bevy::ecs::{
     prelude::*,
-    system::{StaticSystemParam, SystemParam, SystemParamItem},
+    system::{ReadOnlySystemParam, StaticSystemParam, SystemParam, SystemParamItem},
 };
-use bevy::render::render_asset::{PrepareAssetError, PrepareAssetLabel, RenderAsset};
+use bevy::render::render_asset::{PrepareAssetError, PrepareAssetLabel, RenderAsset, RenderAssets};
 use bevy::render::{Extract, RenderApp, RenderStage};
-use bevy::utils::{HashMap, HashSet};
+use bevy::utils::HashMap;
 use bevy_map_handle::MapHandle;
 use std::marker::PhantomData;
+use std::time::{Duration, Instant};
 
 /// Describes how an asset gets extracted and prepared for rendering into [`RenderAsset::PreparedAsset`] of an existing `RenderAsset` specified by [`IntoRenderAsset::Into`].
 ///
@@ -25,10 +26,28 @@ pub trait IntoRenderAsset: Asset {
     /// Specifies all ECS data required by [`IntoRenderAsset::prepare_asset_into`].
     /// For convenience use the [`lifetimeless`](bevy::ecs::system::lifetimeless) [`SystemParam`].
     type Param: SystemParam;
+    /// Specifies all `MainWorld` ECS data required by [`IntoRenderAsset::extract_asset`]. Must be read-only, since
+    /// it is accessed from the `RenderWorld` during [`RenderStage::Extract`](crate::RenderStage::Extract) via [`Extract`].
+    /// For convenience use the [`lifetimeless`](bevy::ecs::system::lifetimeless) [`SystemParam`].
+    type ExtractParam: ReadOnlySystemParam;
+    /// A [`RenderAsset`] this asset may depend on being already prepared, declared via [`IntoRenderAsset::dependencies`].
+    /// If there are no such dependencies, [`IntoRenderAsset::Into`] is a convenient choice since it is always in scope.
+    type Dependency: RenderAsset;
     /// Transforms this asset into [`IntoRenderAsset::ExtractedAsset`].
-    fn extract_asset(&self) -> Self::ExtractedAsset;
+    /// `param` gives read-only access to `MainWorld` ECS data, e.g. to fold component data associated with `handle` into the extracted representation.
+    fn extract_asset(
+        &self,
+        handle: &Handle<Self>,
+        param: &SystemParamItem<Self::ExtractParam>,
+    ) -> Self::ExtractedAsset;
+    /// Declares [`RenderAsset::PreparedAsset`]s of [`IntoRenderAsset::Dependency`] that must already be prepared before
+    /// [`IntoRenderAsset::prepare_asset_into`] is called with `extracted`. Checked every frame, so a dependency that
+    /// only becomes available later unblocks preparation as soon as it does. Defaults to no dependencies.
+    fn dependencies(_extracted: &Self::ExtractedAsset) -> Vec<Handle<Self::Dependency>> {
+        Vec::new()
+    }
     /// Prepares [`IntoRenderAsset::ExtractedAsset`] for the GPU by transforming it into [`RenderAsset::PreparedAsset`] of [`IntoRenderAsset::Into`].
-    /// Therefore ECS data may be accessed via the `param`.
+    /// Therefore ECS data may be accessed via the `param`. Only ever called once every dependency declared by [`IntoRenderAsset::dependencies`] is ready.
     fn prepare_asset_into(
         extracted_asset: Self::ExtractedAsset,
         param: &mut SystemParamItem<Self::Param>,
@@ -36,12 +55,15 @@ pub trait IntoRenderAsset: Asset {
 }
 
 /// This plugin extracts the changed assets from the `MainWorld` of type `T` and prepares them in the `RenderWorld` into [`RenderAsset::PreparedAsset`] of type `U`.
-/// They can be accessed from [`bevy::render::render_asset::RenderAssets<U>`] or [`IntoRenderAssets<T>`].
+/// They can always be accessed from [`IntoRenderAssets<T>`], and additionally from [`bevy::render::render_asset::RenderAssets<U>`] if
+/// [`IntoRenderAssetPlugin::share_into_render_assets`] is enabled.
 ///
 /// It therefore sets up the [`RenderStage::Extract`](crate::RenderStage::Extract) and
 /// [`RenderStage::Prepare`](crate::RenderStage::Prepare) steps for the specified [`IntoRenderAsset`].
 pub struct IntoRenderAssetPlugin<A: IntoRenderAsset> {
     prepare_asset_label: PrepareAssetLabel,
+    prepare_asset_budget: PrepareAssetBudget,
+    share_into_render_assets: Option<fn(&mut App, PrepareAssetLabel)>,
     phantom: PhantomData<fn() -> A>,
 }
 
@@ -49,15 +71,40 @@ impl<A: IntoRenderAsset> IntoRenderAssetPlugin<A> {
     pub fn with_prepare_asset_label(prepare_asset_label: PrepareAssetLabel) -> Self {
         Self {
             prepare_asset_label,
-            phantom: PhantomData,
+            ..Default::default()
         }
     }
+
+    /// Caps how much work [`prepare_assets`] does in a single frame, deferring the remainder to the next frame:
+    /// leftover retries stay in the [`PrepareNextFrameAssets`] retry queue, and extracted assets not yet visited
+    /// stay in [`ExtractedAssets`]. Useful when a burst of changed assets
+    /// (e.g. a streamed-in scene) would otherwise prepare synchronously in one frame and cause a visible hitch.
+    /// Defaults to [`PrepareAssetBudget::Unlimited`].
+    pub fn with_prepare_asset_budget(mut self, prepare_asset_budget: PrepareAssetBudget) -> Self {
+        self.prepare_asset_budget = prepare_asset_budget;
+        self
+    }
+
+    /// Additionally mirrors every prepared asset into the canonical [`RenderAssets<A::Into>`](RenderAssets), keyed by the same [`Handle<A::Into>`](Handle),
+    /// so render features that already read [`RenderAssets<A::Into>`](RenderAssets) (e.g. `bevy_pbr::material`) pick it up without a custom render command.
+    ///
+    /// Requires [`RenderAsset::PreparedAsset`] of [`IntoRenderAsset::Into`] to implement [`Clone`], since the prepared asset now lives in both maps.
+    /// If the base `RenderAssetPlugin<A::Into>` hasn't been added, this is a no-op.
+    pub fn share_into_render_assets(mut self) -> Self
+    where
+        <A::Into as RenderAsset>::PreparedAsset: Clone,
+    {
+        self.share_into_render_assets = Some(add_shared_render_asset_system::<A>);
+        self
+    }
 }
 
 impl<A: IntoRenderAsset> Default for IntoRenderAssetPlugin<A> {
     fn default() -> Self {
         Self {
             prepare_asset_label: Default::default(),
+            prepare_asset_budget: Default::default(),
+            share_into_render_assets: None,
             phantom: PhantomData,
         }
     }
@@ -79,18 +126,107 @@ impl<A: IntoRenderAsset> Plugin for IntoRenderAssetPlugin<A> {
             };
 
             render_app
+                .insert_resource(PrepareAssetBudgetConfig::<A>::new(self.prepare_asset_budget))
                 .init_resource::<ExtractedAssets<A>>()
                 .init_resource::<IntoRenderAssets<A>>()
                 .init_resource::<PrepareNextFrameAssets<A>>()
+                .init_resource::<PreparedAssetChanges<A>>()
+                .init_resource::<RenderAssetRefCounts<A::Into>>()
+                .init_resource::<PreparedAssetOwners<A>>()
                 .add_system_to_stage(RenderStage::Extract, extract_render_asset::<A>)
                 .add_system_to_stage(RenderStage::Prepare, prepare_asset_system);
         }
+
+        if let Some(add_shared_render_asset_system) = self.share_into_render_assets {
+            add_shared_render_asset_system(app, self.prepare_asset_label.clone());
+        }
+    }
+}
+
+/// Adds the system that mirrors [`IntoRenderAssets<A>`] into the canonical [`RenderAssets<A::Into>`](RenderAssets).
+/// Factored out of [`IntoRenderAssetPlugin::share_into_render_assets`] so the `Clone` bound it requires
+/// is proven once, at the call site that enables the feature, rather than on every user of the plugin.
+fn add_shared_render_asset_system<A: IntoRenderAsset>(app: &mut App, prepare_asset_label: PrepareAssetLabel)
+where
+    <A::Into as RenderAsset>::PreparedAsset: Clone,
+{
+    if let Ok(render_app) = app.get_sub_app_mut(RenderApp) {
+        let system = share_into_render_assets::<A>.after(prepare_asset_label);
+        render_app.add_system_to_stage(RenderStage::Prepare, system);
+    }
+}
+
+/// Caps how much work [`prepare_assets`] does in a single frame, set via
+/// [`IntoRenderAssetPlugin::with_prepare_asset_budget`].
+#[derive(Clone, Copy, Debug)]
+pub enum PrepareAssetBudget {
+    /// No cap; every ready asset is prepared every frame.
+    Unlimited,
+    /// Prepare at most this many assets per frame.
+    Count(usize),
+    /// Start no new preparation once this much wall-clock time has passed since the frame's preparation began;
+    /// a preparation already under way when the time runs out is finished, so the frame can run slightly over.
+    Duration(Duration),
+}
+
+impl Default for PrepareAssetBudget {
+    fn default() -> Self {
+        Self::Unlimited
+    }
+}
+
+/// Holds the [`PrepareAssetBudget`] an [`IntoRenderAssetPlugin<A>`] was configured with, so [`prepare_assets`] can
+/// read it without the budget itself needing to be generic over `A`.
+struct PrepareAssetBudgetConfig<A: IntoRenderAsset> {
+    budget: PrepareAssetBudget,
+    phantom: PhantomData<fn() -> A>,
+}
+
+impl<A: IntoRenderAsset> PrepareAssetBudgetConfig<A> {
+    fn new(budget: PrepareAssetBudget) -> Self {
+        Self {
+            budget,
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// Tracks remaining budget over the course of one [`prepare_assets`] call.
+enum PrepareBudgetTracker {
+    Unlimited,
+    Count(usize),
+    Deadline(Instant),
+}
+
+impl PrepareBudgetTracker {
+    fn start(budget: PrepareAssetBudget) -> Self {
+        match budget {
+            PrepareAssetBudget::Unlimited => Self::Unlimited,
+            PrepareAssetBudget::Count(count) => Self::Count(count),
+            PrepareAssetBudget::Duration(duration) => Self::Deadline(Instant::now() + duration),
+        }
+    }
+
+    fn has_remaining(&self) -> bool {
+        match self {
+            Self::Unlimited => true,
+            Self::Count(remaining) => *remaining > 0,
+            Self::Deadline(deadline) => Instant::now() < *deadline,
+        }
+    }
+
+    fn consume_one(&mut self) {
+        if let Self::Count(remaining) = self {
+            *remaining -= 1;
+        }
     }
 }
 
-/// Temporarily stores the extracted and removed assets of the current frame.
+/// Persistently stores assets extracted but not yet prepared, keyed by the (weak) `Handle<A>` they were extracted from,
+/// plus the handles removed since the last prepare. Mutated in place by [`extract_render_asset`] frame to frame
+/// instead of being rebuilt from scratch, so its backing allocations are reused rather than reallocated every frame.
 pub struct ExtractedAssets<A: IntoRenderAsset> {
-    extracted: Vec<(Handle<A>, A::ExtractedAsset)>,
+    extracted: HashMap<Handle<A>, A::ExtractedAsset>,
     removed: Vec<Handle<A>>,
 }
 
@@ -109,99 +245,332 @@ pub type IntoRenderAssets<A> = HashMap<
     <<A as IntoRenderAsset>::Into as RenderAsset>::PreparedAsset,
 >;
 
-/// This system extracts created or modified assets into the `RenderWorld`.
+/// This system extracts created or modified assets into the `RenderWorld`, applying each `AssetEvent` directly to
+/// the persistent [`ExtractedAssets<A>`] instead of collecting into fresh collections and replacing the resource.
 fn extract_render_asset<A: IntoRenderAsset>(
-    mut commands: Commands,
+    mut extracted_assets: ResMut<ExtractedAssets<A>>,
     mut events: Extract<EventReader<AssetEvent<A>>>,
     assets: Extract<Res<Assets<A>>>,
+    param: Extract<StaticSystemParam<A::ExtractParam>>,
 ) {
-    let mut changed_assets = HashSet::default();
-    let mut removed = Vec::new();
+    let param = param.into_inner();
+
     for event in events.iter() {
         match event {
             AssetEvent::Created { handle } | AssetEvent::Modified { handle } => {
-                changed_assets.insert(handle.clone_weak());
+                if let Some(asset) = assets.get(handle) {
+                    extracted_assets
+                        .extracted
+                        .insert(handle.clone_weak(), asset.extract_asset(handle, &param));
+                }
             }
             AssetEvent::Removed { handle } => {
-                changed_assets.remove(&handle);
-                removed.push(handle.clone_weak());
+                extracted_assets.extracted.remove(handle);
+                extracted_assets.removed.push(handle.clone_weak());
             }
         }
     }
+}
 
-    let mut extracted_assets = Vec::new();
-    for handle in changed_assets.drain() {
-        if let Some(asset) = assets.get(&handle) {
-            extracted_assets.push((handle, asset.extract_asset()));
+/// Assets queued to be prepared next frame, double-buffered so `prepare_assets` can drain `current` while pushing
+/// this frame's retries onto `next` without either side reallocating: they swap at the end of every prepare, so the
+/// drained (now empty but still allocated) buffer becomes `next` for the following frame.
+pub struct PrepareNextFrameAssets<A: IntoRenderAsset> {
+    current: Vec<(Handle<A>, A::ExtractedAsset)>,
+    next: Vec<(Handle<A>, A::ExtractedAsset)>,
+}
+
+impl<A: IntoRenderAsset> Default for PrepareNextFrameAssets<A> {
+    fn default() -> Self {
+        Self {
+            current: Default::default(),
+            next: Default::default(),
         }
     }
+}
 
-    commands.insert_resource(ExtractedAssets {
-        extracted: extracted_assets,
-        removed,
-    });
+/// Tracks which entries of [`IntoRenderAssets<A>`] were inserted or removed this frame, so
+/// [`share_into_render_assets`] knows what to mirror into [`RenderAssets<A::Into>`](RenderAssets) without
+/// requiring [`RenderAsset::PreparedAsset`] of [`IntoRenderAsset::Into`] to implement [`Clone`] in [`prepare_assets`] itself.
+struct PreparedAssetChanges<A: IntoRenderAsset> {
+    inserted: Vec<Handle<<A as IntoRenderAsset>::Into>>,
+    removed: Vec<Handle<<A as IntoRenderAsset>::Into>>,
 }
 
-/// Assets queued to be prepared next frame.
-pub struct PrepareNextFrameAssets<A: IntoRenderAsset> {
-    assets: Vec<(Handle<A>, A::ExtractedAsset)>,
+impl<A: IntoRenderAsset> Default for PreparedAssetChanges<A> {
+    fn default() -> Self {
+        Self {
+            inserted: Default::default(),
+            removed: Default::default(),
+        }
+    }
 }
 
-impl<A: IntoRenderAsset> Default for PrepareNextFrameAssets<A> {
+/// How many still-live source assets are mapped onto each `Handle<U>` in [`IntoRenderAssets`]. Keyed by `U` rather than
+/// by the source [`IntoRenderAsset`] type, so that two different source types (or two source assets of the same type)
+/// which happen to map onto the same target handle share one count, and the target is only dropped once nothing
+/// references it anymore.
+struct RenderAssetRefCounts<U: RenderAsset> {
+    counts: HashMap<Handle<U>, usize>,
+}
+
+impl<U: RenderAsset> Default for RenderAssetRefCounts<U> {
+    fn default() -> Self {
+        Self {
+            counts: Default::default(),
+        }
+    }
+}
+
+impl<U: RenderAsset> RenderAssetRefCounts<U> {
+    fn increment(&mut self, handle: Handle<U>) {
+        *self.counts.entry(handle).or_insert(0) += 1;
+    }
+
+    /// Decrements the count for `handle`, returning `true` once nothing references it anymore.
+    fn decrement(&mut self, handle: &Handle<U>) -> bool {
+        match self.counts.get_mut(handle) {
+            Some(count) => {
+                *count -= 1;
+                let dropped = *count == 0;
+                if dropped {
+                    self.counts.remove(handle);
+                }
+                dropped
+            }
+            None => true,
+        }
+    }
+}
+
+/// Tracks which mapped `Handle<R::Into>` each source `Handle<R>` currently holds a reference count for,
+/// so [`prepare_assets`] can correctly increment/decrement [`RenderAssetRefCounts<R::Into>`] as assets
+/// are re-prepared, remapped or removed, instead of just on every successful prepare.
+struct PreparedAssetOwners<R: IntoRenderAsset> {
+    owners: HashMap<Handle<R>, Handle<<R as IntoRenderAsset>::Into>>,
+}
+
+impl<R: IntoRenderAsset> Default for PreparedAssetOwners<R> {
     fn default() -> Self {
         Self {
-            assets: Default::default(),
+            owners: Default::default(),
+        }
+    }
+}
+
+/// Inserts a freshly prepared asset, adjusting [`RenderAssetRefCounts`] for `source`'s mapped target and
+/// only actually removing the previously mapped target, if any, once nothing else references it anymore.
+fn insert_prepared_asset<R: IntoRenderAsset>(
+    source: Handle<R>,
+    mapped: Handle<R::Into>,
+    prepared_asset: <R::Into as RenderAsset>::PreparedAsset,
+    render_assets: &mut IntoRenderAssets<R>,
+    ref_counts: &mut RenderAssetRefCounts<R::Into>,
+    owners: &mut PreparedAssetOwners<R>,
+    changes: &mut PreparedAssetChanges<R>,
+) {
+    match owners.owners.insert(source, mapped.clone_weak()) {
+        Some(previous) if previous == mapped => {}
+        Some(previous) => {
+            if ref_counts.decrement(&previous) {
+                render_assets.remove(&previous);
+                changes.removed.push(previous);
+            }
+            ref_counts.increment(mapped.clone_weak());
+        }
+        None => ref_counts.increment(mapped.clone_weak()),
+    }
+
+    render_assets.insert(mapped.clone_weak(), prepared_asset);
+    changes.inserted.push(mapped);
+}
+
+/// Drops `source`'s reference to its mapped target, if any, only actually removing the
+/// target from [`IntoRenderAssets`] once nothing else references it anymore.
+fn remove_prepared_asset<R: IntoRenderAsset>(
+    source: &Handle<R>,
+    render_assets: &mut IntoRenderAssets<R>,
+    ref_counts: &mut RenderAssetRefCounts<R::Into>,
+    owners: &mut PreparedAssetOwners<R>,
+    changes: &mut PreparedAssetChanges<R>,
+) {
+    if let Some(mapped) = owners.owners.remove(source) {
+        if ref_counts.decrement(&mapped) {
+            render_assets.remove(&mapped);
+            changes.removed.push(mapped);
+        }
+    }
+}
+
+/// Prepares a single extracted asset, unless a dependency declared via [`IntoRenderAsset::dependencies`] isn't ready
+/// yet, in which case it is pushed onto `next_frame` unprepared so it is re-checked next frame.
+/// `dependencies` is `None` if `RenderAssetPlugin<R::Dependency>` hasn't been added; any declared dependency is then
+/// never ready, same as if the resource existed but didn't contain it yet.
+#[allow(clippy::too_many_arguments)]
+fn try_prepare_asset<R: IntoRenderAsset>(
+    handle: Handle<R>,
+    extracted_asset: R::ExtractedAsset,
+    param: &mut SystemParamItem<R::Param>,
+    dependencies: Option<&RenderAssets<R::Dependency>>,
+    render_assets: &mut IntoRenderAssets<R>,
+    ref_counts: &mut RenderAssetRefCounts<R::Into>,
+    owners: &mut PreparedAssetOwners<R>,
+    changes: &mut PreparedAssetChanges<R>,
+    next_frame: &mut Vec<(Handle<R>, R::ExtractedAsset)>,
+) {
+    let declared_dependencies = R::dependencies(&extracted_asset);
+    let ready = match dependencies {
+        Some(dependencies) => declared_dependencies
+            .iter()
+            .all(|dependency| dependencies.get(dependency).is_some()),
+        None => declared_dependencies.is_empty(),
+    };
+    if !ready {
+        next_frame.push((handle, extracted_asset));
+        return;
+    }
+
+    match R::prepare_asset_into(extracted_asset, param) {
+        Ok(prepared_asset) => {
+            let mapped_handle = match handle.map_weak() {
+                Err(_) => panic!("Shouldn't be preparing pending assets."),
+                Ok(handle) => handle,
+            };
+
+            insert_prepared_asset(
+                handle,
+                mapped_handle,
+                prepared_asset,
+                render_assets,
+                ref_counts,
+                owners,
+                changes,
+            );
+        }
+        Err(PrepareAssetError::RetryNextUpdate(extracted_asset)) => {
+            next_frame.push((handle, extracted_asset));
         }
     }
 }
 
 /// This system prepares [`IntoRenderAsset`] assets into [`RenderAsset::PreparedAsset`] of [`IntoRenderAsset::Into`] if extracted this frame or failed to prepare previously.
+/// Assets whose [`IntoRenderAsset::dependencies`] aren't ready yet are deferred rather than prepared, and re-checked every frame.
+/// Only the dirty entries of [`ExtractedAssets`] and the retry queue are visited; nothing is rebuilt from scratch.
+/// Stops preparing once [`PrepareAssetBudget`] is exhausted, leaving anything left over for the next frame's retry queue
+/// or, for not-yet-visited entries of [`ExtractedAssets`], simply in place to be picked up then.
+/// Before preparing anything, the retry queue is purged of entries whose source was removed since they were queued
+/// (e.g. blocked on an unready dependency, or retried after `PrepareAssetError::RetryNextUpdate`); otherwise a retry
+/// that only resolves later would call `prepare_asset_into` for a source that no longer exists in `Assets<R>`, leaking
+/// its `PreparedAsset` forever since `AssetEvent::Removed` only fires once. It is likewise purged of entries made
+/// stale by a fresher re-extraction of the same handle sitting in `extracted_assets.extracted`, so a modified asset
+/// isn't prepared twice from two different extractions in the same call.
+#[allow(clippy::too_many_arguments)]
 fn prepare_assets<R: IntoRenderAsset>(
     mut extracted_assets: ResMut<ExtractedAssets<R>>,
     mut render_assets: ResMut<IntoRenderAssets<R>>,
     mut prepare_next_frame: ResMut<PrepareNextFrameAssets<R>>,
+    mut changes: ResMut<PreparedAssetChanges<R>>,
+    mut ref_counts: ResMut<RenderAssetRefCounts<R::Into>>,
+    mut owners: ResMut<PreparedAssetOwners<R>>,
+    dependencies: Option<Res<RenderAssets<R::Dependency>>>,
     param: StaticSystemParam<<R as IntoRenderAsset>::Param>,
+    budget: Res<PrepareAssetBudgetConfig<R>>,
 ) {
+    changes.inserted.clear();
+    changes.removed.clear();
+
     let mut param = param.into_inner();
-    let mut queued_assets = std::mem::take(&mut prepare_next_frame.assets);
-    for (handle, extracted_asset) in queued_assets.drain(..) {
-        match R::prepare_asset_into(extracted_asset, &mut param) {
-            Ok(prepared_asset) => {
-                let handle = match handle.map_weak() {
-                    Err(_) => panic!("Shouldn't be preparing pending assets."),
-                    Ok(handle) => handle,
-                };
-
-                render_assets.insert(handle, prepared_asset);
-            }
-            Err(PrepareAssetError::RetryNextUpdate(extracted_asset)) => {
-                prepare_next_frame.assets.push((handle, extracted_asset));
-            }
-        }
-    }
+    let mut budget = PrepareBudgetTracker::start(budget.budget);
+    let PrepareNextFrameAssets { current, next } = &mut *prepare_next_frame;
+
+    let is_stale = |handle: &Handle<R>| {
+        extracted_assets.removed.contains(handle) || extracted_assets.extracted.contains_key(handle)
+    };
+    current.retain(|(handle, _)| !is_stale(handle));
+    next.retain(|(handle, _)| !is_stale(handle));
 
-    for removed in std::mem::take(&mut extracted_assets.removed) {
-        let handle = match removed.map_weak() {
-            Err(_) => panic!("Shouldn't be removing pending assets."),
-            Ok(handle) => handle,
+    while budget.has_remaining() {
+        let (handle, extracted_asset) = match current.pop() {
+            Some(entry) => entry,
+            None => break,
         };
+        try_prepare_asset(
+            handle,
+            extracted_asset,
+            &mut param,
+            dependencies.as_deref(),
+            &mut render_assets,
+            &mut ref_counts,
+            &mut owners,
+            &mut changes,
+            next,
+        );
+        budget.consume_one();
+    }
+
+    for removed in extracted_assets.removed.drain(..) {
+        remove_prepared_asset(
+            &removed,
+            &mut render_assets,
+            &mut ref_counts,
+            &mut owners,
+            &mut changes,
+        );
+    }
 
-        render_assets.remove(&handle);
+    while budget.has_remaining() {
+        let handle = match extracted_assets.extracted.keys().next() {
+            Some(handle) => handle.clone_weak(),
+            None => break,
+        };
+        let extracted_asset = extracted_assets.extracted.remove(&handle).unwrap();
+        try_prepare_asset(
+            handle,
+            extracted_asset,
+            &mut param,
+            dependencies.as_deref(),
+            &mut render_assets,
+            &mut ref_counts,
+            &mut owners,
+            &mut changes,
+            next,
+        );
+        budget.consume_one();
     }
 
-    for (handle, extracted_asset) in std::mem::take(&mut extracted_assets.extracted) {
-        match R::prepare_asset_into(extracted_asset, &mut param) {
-            Ok(prepared_asset) => {
-                let handle = match handle.map_weak() {
-                    Err(_) => panic!("Shouldn't be preparing pending assets."),
-                    Ok(handle) => handle,
-                };
+    // Anything left in `current` ran out of budget before being retried; fold it into `next` so the
+    // swap below hands it back as part of next frame's retry queue instead of losing it.
+    next.append(current);
+    std::mem::swap(current, next);
+}
 
-                render_assets.insert(handle, prepared_asset);
-            }
-            Err(PrepareAssetError::RetryNextUpdate(extracted_asset)) => {
-                prepare_next_frame.assets.push((handle, extracted_asset));
-            }
+/// This system mirrors the entries of [`IntoRenderAssets<A>`] that changed this frame into the canonical
+/// [`RenderAssets<A::Into>`](RenderAssets), so render features that consume it directly (e.g. `bevy_pbr::material`)
+/// see assets produced through [`IntoRenderAsset`] without a custom render command.
+/// Added by [`IntoRenderAssetPlugin::share_into_render_assets`]; a no-op if the base `RenderAssetPlugin<A::Into>` isn't present.
+fn share_into_render_assets<A: IntoRenderAsset>(
+    render_assets: Res<IntoRenderAssets<A>>,
+    changes: Res<PreparedAssetChanges<A>>,
+    shared_render_assets: Option<ResMut<RenderAssets<A::Into>>>,
+) where
+    <A::Into as RenderAsset>::PreparedAsset: Clone,
+{
+    let mut shared_render_assets = match shared_render_assets {
+        Some(shared_render_assets) => shared_render_assets,
+        None => return,
+    };
+
+    // `changes.removed` and `changes.inserted` come from separate passes within the same `prepare_assets` call
+    // (retries/extracted-inserts happen before and after the single removal pass), so the same handle can appear
+    // in both when it's dropped by one owner and reclaimed by another in the same frame. Apply removals first so
+    // that case ends up inserted, matching the final state of `IntoRenderAssets<A>` rather than diverging from it.
+    for handle in &changes.removed {
+        shared_render_assets.remove(handle);
+    }
+
+    for handle in &changes.inserted {
+        if let Some(prepared_asset) = render_assets.get(handle) {
+            shared_render_assets.insert(handle.clone_weak(), prepared_asset.clone());
         }
     }
 }